@@ -1,5 +1,38 @@
-use core::{mem, ptr};
-use std::alloc::{alloc, dealloc, realloc, Layout};
+use core::{fmt, mem, ptr};
+use std::alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout};
+use std::error::Error;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+/// The error type returned by the fallible reservation methods of [`Deque`]
+/// when the requested capacity cannot be satisfied.
+// ANCHOR: TryReserveError
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The computed capacity overflows `usize` or exceeds `isize::MAX` bytes.
+    CapacityOverflow,
+    /// The memory allocator returned an error.
+    AllocError {
+        /// The layout that was requested from the allocator.
+        layout: Layout,
+    },
+}
+// ANCHOR_END: TryReserveError
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => {
+                write!(f, "capacity overflow")
+            }
+            TryReserveError::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl Error for TryReserveError {}
 
 // A double-ended queue (abbreviated to _deque_), for which elements can be
 // added or remove from both back and front ends.
@@ -26,6 +59,11 @@ pub struct Deque<T> {
 /// buffer expansions easily. This value must be power of 2.
 const DEFAULT_CAPACITY: usize = 1;
 
+/// The ring buffer capacity used for a [`Deque`] of zero-sized elements,
+/// which never needs to allocate. Must be a power of 2, same as any other
+/// capacity. [`Deque::capacity`] reports one less, as for any other buffer.
+const MAXIMUM_ZST_CAPACITY: usize = 1 << (usize::BITS - 1);
+
 impl<T> Deque<T> {
     /// Constructs a new, empty [`Deque<T>`].
     ///
@@ -40,6 +78,23 @@ impl<T> Deque<T> {
     }
     // ANCHOR_END: new
 
+    /// Constructs a new, empty [`Deque<T>`] with space for at least `capacity`
+    /// elements without reallocating.
+    ///
+    /// One slack slot is always kept to distinguish a full buffer from an
+    /// empty one, so the underlying ring buffer allocates `capacity + 1`
+    /// slots rounded up to the next power of two.
+    // ANCHOR: with_capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        let cap = (capacity + 1).next_power_of_two();
+        Self {
+            tail: 0,
+            head: 0,
+            ring_buf: RawVec::with_capacity(cap),
+        }
+    }
+    // ANCHOR_END: with_capacity
+
     /// Prepends the given element value to the beginning of the container.
     ///
     /// # Parameters
@@ -145,6 +200,104 @@ impl<T> Deque<T> {
     }
     // ANCHOR_END: back
 
+    /// Returns a pair of slices which contain, in order, the elements of the
+    /// container.
+    ///
+    /// Since the occupied region of the underlying ring buffer may wrap
+    /// around its end, the elements cannot always be viewed as a single
+    /// slice. The first slice holds the elements closest to the front, and
+    /// the second slice, if non-empty, holds the rest.
+    ///
+    /// # Complexity
+    ///
+    /// Constant.
+    // ANCHOR: as_slices
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        // This is safe because `ring_slices` only ever hands back indices
+        // inside the occupied region `[tail, head)`.
+        unsafe {
+            let (first, second) = self.ring_slices(self.ptr());
+            (&*first, &*second)
+        }
+    }
+    // ANCHOR_END: as_slices
+
+    /// Returns a pair of mutable slices which contain, in order, the elements
+    /// of the container.
+    ///
+    /// See [`Deque::as_slices`] for details on why there can be two slices.
+    ///
+    /// # Complexity
+    ///
+    /// Constant.
+    // ANCHOR: as_mut_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        // This is safe because `ring_slices` only ever hands back indices
+        // inside the occupied region `[tail, head)`, which cannot overlap.
+        unsafe {
+            let (first, second) = self.ring_slices(self.ptr());
+            (&mut *first, &mut *second)
+        }
+    }
+    // ANCHOR_END: as_mut_slices
+
+    /// Rearranges the underlying ring buffer so that the elements are
+    /// contiguous, then returns a mutable slice over them.
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: make_contiguous
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.tail > self.head {
+            // The head segment `[0, head)` and tail segment `[tail, cap)`
+            // are in the wrong order relative to each other (the tail
+            // segment holds the logically-earlier elements). Slide the tail
+            // segment across the gap so it sits directly after the head
+            // segment, then swap the two segments into place in-order with
+            // the standard three-reversal rotation.
+            //
+            // Before:
+            //          h   t
+            // [o o o o x x o o]
+            //
+            // Slide (the tail segment `T` now sits right after the head
+            // segment `H`):
+            // [H H H H T T x x]
+            //
+            // Reverse each half, then the whole run, to swap their order:
+            //  t           h
+            // [T T H H H H x x]
+            let h = self.head;
+            let t = self.cap() - self.tail;
+            let len = h + t;
+            let ptr = self.ptr();
+
+            // This is safe because `[self.tail, self.tail + t)` is fully
+            // initialized, and `ptr::copy` (unlike `copy_nonoverlapping`)
+            // tolerates the destination range overlapping it, which happens
+            // whenever the gap is smaller than the tail segment.
+            unsafe {
+                ptr::copy(ptr.add(self.tail), ptr.add(h), t);
+            }
+
+            // This is safe because the slide above leaves `[0, len)` one
+            // contiguous run of initialized elements.
+            let buf = unsafe { slice::from_raw_parts_mut(ptr, len) };
+            buf[..h].reverse();
+            buf[h..].reverse();
+            buf.reverse();
+
+            self.tail = 0;
+            self.head = len;
+        }
+
+        // This is safe because after the rotation above the occupied region
+        // is the single contiguous run `[tail, head)`.
+        unsafe { slice::from_raw_parts_mut(self.ptr().add(self.tail), self.len()) }
+    }
+    // ANCHOR_END: make_contiguous
+
     ///	Checks whether the container is empty.
     ///
     /// # Complexity
@@ -174,6 +327,108 @@ impl<T> Deque<T> {
     }
     // ANCHOR_END: is_full
 
+    /// Returns the number of elements the container can hold without
+    /// reallocating.
+    ///
+    /// # Complexity
+    ///
+    /// Constant.
+    // ANCHOR: capacity
+    pub fn capacity(&self) -> usize {
+        self.cap() - 1
+    }
+    // ANCHOR_END: capacity
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted into the container.
+    ///
+    /// Because the underlying ring buffer's capacity must always be a power
+    /// of two, this grows to the same target as [`Deque::reserve_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`. Aborts on allocation
+    /// failure; see [`Deque::try_reserve`] for a fallible version.
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: reserve
+    pub fn reserve(&mut self, additional: usize) {
+        self.reserve_exact(additional);
+    }
+    // ANCHOR_END: reserve
+
+    /// Reserves the minimum capacity for at least `additional` more elements
+    /// to be inserted into the container, growing the ring buffer in a
+    /// single reallocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`. Aborts on allocation
+    /// failure; see [`Deque::try_reserve_exact`] for a fallible version.
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: reserve_exact
+    pub fn reserve_exact(&mut self, additional: usize) {
+        match self.try_reserve_exact(additional) {
+            Ok(()) => {}
+            Err(TryReserveError::CapacityOverflow) => panic!("capacity overflow"),
+            Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+        }
+    }
+    // ANCHOR_END: reserve_exact
+
+    /// Tries to reserve capacity for at least `additional` more elements to
+    /// be inserted into the container, returning an error instead of
+    /// aborting if the allocation fails.
+    ///
+    /// Because the underlying ring buffer's capacity must always be a power
+    /// of two, this grows to the same target as [`Deque::try_reserve_exact`].
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: try_reserve
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_reserve_exact(additional)
+    }
+    // ANCHOR_END: try_reserve
+
+    /// Tries to reserve the minimum capacity for at least `additional` more
+    /// elements to be inserted into the container, growing the ring buffer
+    /// in a single reallocation and returning an error instead of aborting
+    /// if the allocation fails.
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: try_reserve_exact
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let needed = self
+            .len()
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let capacity = needed
+            .checked_add(1)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        // `next_power_of_two()` overflows `usize` (panicking in debug,
+        // wrapping to 0 in release) once `capacity` exceeds the largest
+        // representable power of two, so reject it up front.
+        if capacity > (1usize << (usize::BITS - 1)) {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        let new_cap = capacity.next_power_of_two();
+
+        if new_cap > self.cap() {
+            self.try_grow_to(new_cap)?;
+        }
+        Ok(())
+    }
+    // ANCHOR_END: try_reserve_exact
+
     /// Resizes the underlying ring buffer if necessary.
     ///
     /// # Complexity
@@ -183,38 +438,65 @@ impl<T> Deque<T> {
     // ANCHOR: try_resize
     fn try_resize(&mut self) {
         if self.is_full() {
-            let old_cap = self.cap();
-            self.ring_buf.grow();
-
-            if self.tail > self.head {
-                // Make the ring buffer contiguous.
-                //
-                // The content of ring buffer won't overlapping, so
-                // `copy_nonoverlapping` is safe to called.
-                //
-                // Before:
-                //          h   t
-                // [o o o o x x o o]
-                //
-                // Resize:
-                //          h   t
-                // [o o o o x x o o | x x x x x x x x]
-                //
-                // Copy:
-                //              t           h
-                // [x x x x x x o o | o o o o x x x x]
-                //  _ _ _ _           _ _ _ _
-                unsafe {
-                    let src = self.ptr();
-                    let dst = self.ptr().add(old_cap);
-                    ptr::copy_nonoverlapping(src, dst, self.head);
-                }
-                self.head += old_cap;
-            }
+            self.grow_to(self.cap() * 2);
         }
     }
     // ANCHOR_END: try_resize
 
+    /// Grows the underlying ring buffer to `new_cap`, aborting on allocation
+    /// failure.
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: grow_to
+    fn grow_to(&mut self, new_cap: usize) {
+        self.try_grow_to(new_cap)
+            .unwrap_or_else(|_| handle_alloc_error(Layout::array::<T>(new_cap).unwrap()));
+    }
+    // ANCHOR_END: grow_to
+
+    /// Grows the underlying ring buffer to `new_cap` in a single
+    /// reallocation and, if the occupied region was wrapped, relocates the
+    /// wrapped head segment so the content stays logically ordered.
+    ///
+    /// # Complexity
+    ///
+    /// Linear in the size of the container.
+    // ANCHOR: try_grow_to
+    fn try_grow_to(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        let old_cap = self.cap();
+        self.ring_buf.try_grow(new_cap)?;
+
+        if self.tail > self.head {
+            // Make the ring buffer contiguous.
+            //
+            // The content of ring buffer won't overlapping, so
+            // `copy_nonoverlapping` is safe to called.
+            //
+            // Before:
+            //          h   t
+            // [o o o o x x o o]
+            //
+            // Resize:
+            //          h   t
+            // [o o o o x x o o | x x x x x x x x]
+            //
+            // Copy:
+            //              t           h
+            // [x x x x x x o o | o o o o x x x x]
+            //  _ _ _ _           _ _ _ _
+            unsafe {
+                let src = self.ptr();
+                let dst = self.ptr().add(old_cap);
+                ptr::copy_nonoverlapping(src, dst, self.head);
+            }
+            self.head += old_cap;
+        }
+        Ok(())
+    }
+    // ANCHOR_END: try_grow_to
+
     /// Returns the actual index of the underlying ring buffer for a given
     /// logical index + addend.
     // ANCHOR: wrapping_add
@@ -231,6 +513,26 @@ impl<T> Deque<T> {
     }
     // ANCHOR_END: wrapping_sub
 
+    /// Splits the occupied region `[tail, head)` into the (at most two) raw
+    /// slices it is made of, given a pointer to the start of the buffer.
+    ///
+    /// # Safety
+    ///
+    /// `buf` must point to this deque's ring buffer.
+    // ANCHOR: ring_slices
+    unsafe fn ring_slices(&self, buf: *mut T) -> (*mut [T], *mut [T]) {
+        if self.tail <= self.head {
+            let first = ptr::slice_from_raw_parts_mut(buf.add(self.tail), self.head - self.tail);
+            let second = ptr::slice_from_raw_parts_mut(buf, 0);
+            (first, second)
+        } else {
+            let first = ptr::slice_from_raw_parts_mut(buf.add(self.tail), self.cap() - self.tail);
+            let second = ptr::slice_from_raw_parts_mut(buf, self.head);
+            (first, second)
+        }
+    }
+    // ANCHOR_END: ring_slices
+
     /// An abstraction for accessing the pointer of the ring buffer.
     // ANCHOR: ptr
     #[inline]
@@ -246,6 +548,63 @@ impl<T> Deque<T> {
         self.ring_buf.cap
     }
     // ANCHOR_END: cap
+
+    /// Returns a reference to the element at the given logical index, or
+    /// `None` if `index` is out of bounds.
+    ///
+    /// # Complexity
+    ///
+    /// Constant.
+    // ANCHOR: get
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+        let index = self.wrapping_add(self.tail, index);
+        // This is safe because the offset is wrapped inside available memory by `wrap_index()`.
+        unsafe { Some(&*self.ptr().add(index)) }
+    }
+    // ANCHOR_END: get
+
+    /// Returns a mutable reference to the element at the given logical
+    /// index, or `None` if `index` is out of bounds.
+    ///
+    /// # Complexity
+    ///
+    /// Constant.
+    // ANCHOR: get_mut
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+        let index = self.wrapping_add(self.tail, index);
+        // This is safe because the offset is wrapped inside available memory by `wrap_index()`.
+        unsafe { Some(&mut *self.ptr().add(index)) }
+    }
+    // ANCHOR_END: get_mut
+
+    /// Returns an iterator over the container's elements, from front to back.
+    // ANCHOR: iter
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (first, second) = self.as_slices();
+        Iter {
+            i1: first.iter(),
+            i2: second.iter(),
+        }
+    }
+    // ANCHOR_END: iter
+
+    /// Returns a mutable iterator over the container's elements, from front
+    /// to back.
+    // ANCHOR: iter_mut
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        IterMut {
+            i1: first.iter_mut(),
+            i2: second.iter_mut(),
+        }
+    }
+    // ANCHOR_END: iter_mut
 }
 
 /// Returns the actual index of the underlying ring buffer for a given logical index.
@@ -259,6 +618,185 @@ fn wrap_index(index: usize, size: usize) -> usize {
 }
 // ANCHOR_END: wrap_index
 
+/// An iterator over the elements of a [`Deque`].
+///
+/// This struct is created by [`Deque::iter`].
+// ANCHOR: Iter
+pub struct Iter<'a, T> {
+    i1: slice::Iter<'a, T>,
+    i2: slice::Iter<'a, T>,
+}
+// ANCHOR_END: Iter
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.i1.next().or_else(|| {
+            mem::swap(&mut self.i1, &mut self.i2);
+            self.i1.next()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.i2.next_back().or_else(|| {
+            mem::swap(&mut self.i1, &mut self.i2);
+            self.i2.next_back()
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.i1.len() + self.i2.len()
+    }
+}
+
+/// A mutable iterator over the elements of a [`Deque`].
+///
+/// This struct is created by [`Deque::iter_mut`].
+// ANCHOR: IterMut
+pub struct IterMut<'a, T> {
+    i1: slice::IterMut<'a, T>,
+    i2: slice::IterMut<'a, T>,
+}
+// ANCHOR_END: IterMut
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.i1.next().or_else(|| {
+            mem::swap(&mut self.i1, &mut self.i2);
+            self.i1.next()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.i2.next_back().or_else(|| {
+            mem::swap(&mut self.i1, &mut self.i2);
+            self.i2.next_back()
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.i1.len() + self.i2.len()
+    }
+}
+
+/// An owning iterator over the elements of a [`Deque`].
+///
+/// This struct is created by the [`IntoIterator`] impl for [`Deque<T>`].
+// ANCHOR: IntoIter
+pub struct IntoIter<T> {
+    deque: Deque<T>,
+}
+// ANCHOR_END: IntoIter
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.deque.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.deque.len()
+    }
+}
+
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Deque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Deque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Deque::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> Extend<T> for Deque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> Index<usize> for Deque<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for Deque<T> {
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
 /// A growable, contiguous heap memory allocation that stores homogeneous elements.
 ///
 /// This is a simplified version of [`RawVec`] inside Rust Standard Library.
@@ -276,14 +814,21 @@ struct RawVec<T> {
 impl<T> RawVec<T> {
     /// Allocates on the heap with a certain capacity.
     ///
-    /// Note that this does not support zero-sized allocations.
+    /// `T` being zero-sized is handled separately: no allocation is ever
+    /// needed, so this hands back a dangling, well-aligned pointer and a
+    /// capacity of [`MAXIMUM_ZST_CAPACITY`] regardless of `cap`.
     /// For more, see [The Rustonomicon: Handling Zero-Sized Types][1].
     /// [1]: https://doc.rust-lang.org/nomicon/vec-zsts.html
     // ANCHOR: RawVec_with_capacity
     fn with_capacity(cap: usize) -> Self {
-        let layout = Layout::array::<T>(cap).unwrap();
-        assert!(layout.size() > 0, "Zero-sized allocation is not support");
+        if mem::size_of::<T>() == 0 {
+            return Self {
+                ptr: ptr::NonNull::dangling().as_ptr(),
+                cap: MAXIMUM_ZST_CAPACITY,
+            };
+        }
 
+        let layout = Layout::array::<T>(cap).unwrap();
         // This is safe because it conforms to the [safety contracts][1].
         //
         // [1] https://doc.rust-lang.org/1.49.0/alloc/alloc/trait.GlobalAlloc.html#safety-1
@@ -292,23 +837,69 @@ impl<T> RawVec<T> {
     }
     // ANCHOR_END: RawVec_with_capacity
 
-    // Doubles the size of the memory region to a certain capacity of elements.
+    // Grows the memory region to hold `new_cap` elements in a single
+    // reallocation, returning an error instead of committing a null pointer
+    // on allocation failure.
+    //
+    // `T` being zero-sized never needs to reallocate; the capacity is
+    // already pinned at `MAXIMUM_ZST_CAPACITY`.
     // ANCHOR: RawVec_resize
-    fn grow(&mut self) {
-        let new_cap = if self.cap == 0 { 1 } else { self.cap * 2 };
+    fn try_grow(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        if mem::size_of::<T>() == 0 {
+            return Ok(());
+        }
+
         let old_layout = Layout::array::<T>(self.cap).unwrap();
+        let new_layout =
+            Layout::array::<T>(new_cap).map_err(|_| TryReserveError::CapacityOverflow)?;
         // This is safe because it conforms to the [safety contracts][1].
         //
         // [1] https://doc.rust-lang.org/1.49.0/alloc/alloc/trait.GlobalAlloc.html#safety-4
-        let ptr = unsafe { realloc(self.ptr.cast(), old_layout, old_layout.align() * new_cap) };
+        let ptr = unsafe { realloc(self.ptr.cast(), old_layout, new_layout.size()) };
+        if ptr.is_null() {
+            return Err(TryReserveError::AllocError { layout: new_layout });
+        }
         // ...Old allocation is unusable and may be released from here.
 
         self.ptr = ptr.cast();
         self.cap = new_cap;
+        Ok(())
     }
     // ANCHOR_END: RawVec_resize
 }
 
+// ANCHOR: Deque_drop
+impl<T> Drop for Deque<T> {
+    /// Drops every element still living in the ring buffer before the
+    /// backing [`RawVec`] frees its allocation.
+    ///
+    /// The occupied logical range `[tail, head)` may wrap around the end of
+    /// the buffer, in which case it is split into two contiguous runs.
+    fn drop(&mut self) {
+        let (tail, head) = (self.tail, self.head);
+        let cap = self.cap();
+
+        // This is safe because `tail` and `head` always bound the occupied
+        // region of the ring buffer, and each slot in that region is only
+        // ever dropped once.
+        unsafe {
+            if tail <= head {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    self.ptr().add(tail),
+                    head - tail,
+                ));
+            } else {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    self.ptr().add(tail),
+                    cap - tail,
+                ));
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr(), head));
+            }
+        }
+    }
+}
+// ANCHOR_END: Deque_drop
+
 // ANCHOR: RawVec_drop
 impl<T> Drop for RawVec<T> {
     /// Deallocates the underlying memory region by calculating the type layout
@@ -331,7 +922,7 @@ impl<T> Drop for RawVec<T> {
 
 #[cfg(test)]
 mod deque {
-    use super::Deque;
+    use super::{Deque, TryReserveError};
 
     #[test]
     fn push_pop() {
@@ -377,4 +968,264 @@ mod deque {
         assert_eq!(d.front(), None);
         assert_eq!(d.back(), None);
     }
+
+    // A `Drop`-tracking element: dropping it appends its value to the shared
+    // log.
+    #[repr(transparent)]
+    struct Tracker(std::rc::Rc<(std::rc::Rc<std::cell::RefCell<Vec<i32>>>, i32)>);
+
+    impl Tracker {
+        fn new(log: &std::rc::Rc<std::cell::RefCell<Vec<i32>>>, val: i32) -> Self {
+            Tracker(std::rc::Rc::new((std::rc::Rc::clone(log), val)))
+        }
+    }
+
+    impl Drop for Tracker {
+        fn drop(&mut self) {
+            let (log, val) = &*self.0;
+            log.borrow_mut().push(*val);
+        }
+    }
+
+    #[test]
+    fn drop_runs_for_every_remaining_element() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+
+        let mut d = Deque::new();
+        for i in 0..6 {
+            d.push_back(Tracker::new(&dropped, i));
+        }
+        drop(d.pop_front());
+        drop(d.pop_front());
+
+        assert_eq!(*dropped.borrow(), vec![0, 1]);
+        drop(d);
+        assert_eq!(*dropped.borrow(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn drop_runs_across_the_wrap_boundary() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+
+        // Pushing only to the front moves `tail` back past index 0; with
+        // seven elements in a buffer of eight, `tail` (7) ends up above
+        // `head` (6), so the occupied region `[tail, head)` wraps around.
+        let mut d = Deque::new();
+        for i in 0..7 {
+            d.push_front(Tracker::new(&dropped, i));
+        }
+        assert!(!d.as_slices().1.is_empty());
+
+        drop(d);
+        assert_eq!(dropped.borrow().len(), 7);
+    }
+
+    #[test]
+    fn slices_and_make_contiguous() {
+        let mut d = Deque::new();
+        for i in 0..4 {
+            d.push_back(i);
+        }
+        d.pop_front();
+        d.pop_front();
+        // Pushing past the buffer end wraps `head` below `tail`.
+        for i in 4..9 {
+            d.push_back(i);
+        }
+        // Logical content: [2, 3, 4, 5, 6, 7, 8], physically wrapped.
+        assert!(!d.as_slices().1.is_empty());
+
+        let combined: Vec<i32> = {
+            let (first, second) = d.as_slices();
+            first.iter().chain(second.iter()).copied().collect()
+        };
+        assert_eq!(combined, vec![2, 3, 4, 5, 6, 7, 8]);
+
+        {
+            let (first, second) = d.as_mut_slices();
+            for elem in first.iter_mut().chain(second.iter_mut()) {
+                *elem *= 10;
+            }
+        }
+        let combined: Vec<i32> = {
+            let (first, second) = d.as_slices();
+            first.iter().chain(second.iter()).copied().collect()
+        };
+        assert_eq!(combined, vec![20, 30, 40, 50, 60, 70, 80]);
+
+        assert_eq!(d.make_contiguous(), &[20, 30, 40, 50, 60, 70, 80]);
+        assert_eq!(d.as_slices().1.len(), 0);
+    }
+
+    #[test]
+    fn iter_and_iter_mut() {
+        let mut d = Deque::new();
+        for i in 0..4 {
+            d.push_back(i);
+        }
+        d.pop_front();
+        d.pop_front();
+        for i in 4..9 {
+            d.push_back(i);
+        }
+        // Logical content: [2, 3, 4, 5, 6, 7, 8], wrapped across the buffer end.
+
+        assert_eq!(d.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            d.iter().rev().copied().collect::<Vec<_>>(),
+            vec![8, 7, 6, 5, 4, 3, 2]
+        );
+        assert_eq!(d.iter().len(), 7);
+
+        for elem in d.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(
+            d.iter().copied().collect::<Vec<_>>(),
+            vec![20, 30, 40, 50, 60, 70, 80]
+        );
+    }
+
+    #[test]
+    fn into_iter_from_iter_and_extend() {
+        let mut d: Deque<i32> = Deque::new();
+        for i in 0..4 {
+            d.push_back(i);
+        }
+        d.pop_front();
+        d.pop_front();
+        for i in 4..9 {
+            d.push_back(i);
+        }
+
+        assert_eq!(d.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7, 8]);
+
+        let collected: Deque<i32> = (0..5).collect();
+        assert_eq!(collected.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+        let mut extended: Deque<i32> = (0..3).collect();
+        extended.extend(3..6);
+        assert_eq!(
+            extended.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn get_and_index() {
+        let mut d = Deque::new();
+        for i in 0..4 {
+            d.push_back(i);
+        }
+        d.pop_front();
+        d.pop_front();
+        for i in 4..9 {
+            d.push_back(i);
+        }
+        // Logical content: [2, 3, 4, 5, 6, 7, 8], wrapped across the buffer end.
+
+        assert_eq!(d.get(0), Some(&2));
+        assert_eq!(d.get(6), Some(&8));
+        assert_eq!(d.get(7), None);
+        assert_eq!(d[0], 2);
+        assert_eq!(d[6], 8);
+
+        *d.get_mut(0).unwrap() = 20;
+        d[6] = 80;
+        assert_eq!(d[0], 20);
+        assert_eq!(d[6], 80);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds_panics() {
+        let d: Deque<i32> = Deque::new();
+        let _ = d[0];
+    }
+
+    #[test]
+    fn with_capacity_rounds_up_to_power_of_two() {
+        let d: Deque<i32> = Deque::with_capacity(5);
+        assert_eq!(d.capacity(), 7);
+
+        let d: Deque<i32> = Deque::with_capacity(0);
+        assert_eq!(d.capacity(), 0);
+    }
+
+    #[test]
+    fn reserve_preserves_order_across_the_wrap_boundary() {
+        let mut d = Deque::new();
+        for i in 0..4 {
+            d.push_back(i);
+        }
+        d.pop_front();
+        d.pop_front();
+        // Pushing past the buffer end wraps `head` below `tail`.
+        for i in 4..9 {
+            d.push_back(i);
+        }
+        // Logical content: [2, 3, 4, 5, 6, 7, 8], physically wrapped.
+        let cap_before = d.capacity();
+
+        d.reserve(100);
+        assert!(d.capacity() >= 100 + d.len());
+        assert!(d.capacity() > cap_before);
+        assert_eq!(
+            d.iter().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4, 5, 6, 7, 8]
+        );
+
+        // reserve_exact is a no-op once there is already enough room.
+        let cap_after = d.capacity();
+        d.reserve_exact(1);
+        assert_eq!(d.capacity(), cap_after);
+    }
+
+    #[test]
+    fn try_reserve_succeeds_and_reports_capacity_overflow() {
+        let mut d: Deque<i32> = Deque::new();
+        assert!(d.try_reserve(16).is_ok());
+        assert!(d.capacity() >= 16);
+
+        assert_eq!(
+            d.try_reserve_exact(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+
+        // `len() + additional + 1` is exactly `usize::MAX`, above the largest
+        // representable power of two, where a naive `next_power_of_two()`
+        // would overflow instead of erroring.
+        assert_eq!(
+            d.try_reserve_exact(usize::MAX - 1),
+            Err(TryReserveError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn zero_sized_elements_do_not_allocate() {
+        let mut d = Deque::new();
+        assert_eq!(d.len(), 0);
+
+        for _ in 0..10 {
+            d.push_back(());
+        }
+        assert_eq!(d.len(), 10);
+        assert_eq!(d.front(), Some(&()));
+
+        for _ in 0..10 {
+            assert_eq!(d.pop_front(), Some(()));
+        }
+        assert_eq!(d.pop_front(), None);
+        assert_eq!(d.len(), 0);
+
+        d.push_front(());
+        d.reserve(1000);
+        assert_eq!(d.len(), 1);
+    }
 }